@@ -0,0 +1,5 @@
+pub mod redis;
+pub mod replica;
+
+pub use redis::RedisClient;
+pub use replica::Replicas;
@@ -0,0 +1,96 @@
+use crate::{
+  clients::{Pipeline, Replicas},
+  interfaces::{
+    AuthInterface,
+    ClientLike,
+    FunctionInterface,
+    GeoInterface,
+    HashesInterface,
+    HyperloglogInterface,
+    KeysInterface,
+    ListInterface,
+    LuaInterface,
+    MemoryInterface,
+    MetricsInterface,
+    ServerInterface,
+    SetsInterface,
+    SlowlogInterface,
+    SortedSetsInterface,
+    StreamsInterface,
+  },
+  modules::inner::RedisClientInner,
+  protocol::command::RedisCommand,
+  router::replica_routing,
+};
+use std::{fmt, fmt::Formatter, sync::Arc};
+
+/// A full-featured Redis client.
+///
+/// This is the primary interface most callers use to talk to primary nodes. Use [Replicas](Self::replicas) for an
+/// interface that talks exclusively to replica nodes, or enable
+/// [read_from_replicas](crate::types::config::ReplicaConfig::read_from_replicas) on this client's config to have
+/// it automatically send read-only commands to a replica on its own.
+#[derive(Clone)]
+pub struct RedisClient {
+  inner: Arc<RedisClientInner>,
+}
+
+impl fmt::Debug for RedisClient {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    f.debug_struct("RedisClient").field("id", &self.inner.id).finish()
+  }
+}
+
+#[doc(hidden)]
+impl From<&Arc<RedisClientInner>> for RedisClient {
+  fn from(inner: &Arc<RedisClientInner>) -> Self {
+    RedisClient { inner: inner.clone() }
+  }
+}
+
+impl ClientLike for RedisClient {
+  #[doc(hidden)]
+  fn inner(&self) -> &Arc<RedisClientInner> {
+    &self.inner
+  }
+
+  #[doc(hidden)]
+  fn change_command(&self, command: &mut RedisCommand) {
+    let has_replica = !self.inner.server_state.read().replicas.is_empty();
+
+    command.use_replica = replica_routing::should_use_replica(
+      &self.inner.config.replica,
+      has_replica,
+      command.kind.to_str_debug(),
+      &self.inner.command_info.read(),
+    );
+  }
+}
+
+impl GeoInterface for RedisClient {}
+impl HashesInterface for RedisClient {}
+impl HyperloglogInterface for RedisClient {}
+impl MetricsInterface for RedisClient {}
+impl KeysInterface for RedisClient {}
+impl LuaInterface for RedisClient {}
+impl FunctionInterface for RedisClient {}
+impl ListInterface for RedisClient {}
+impl MemoryInterface for RedisClient {}
+impl AuthInterface for RedisClient {}
+impl ServerInterface for RedisClient {}
+impl SlowlogInterface for RedisClient {}
+impl SetsInterface for RedisClient {}
+impl SortedSetsInterface for RedisClient {}
+impl StreamsInterface for RedisClient {}
+
+impl RedisClient {
+  /// Send a series of commands in a [pipeline](https://redis.io/docs/manual/pipelining/).
+  pub fn pipeline(&self) -> Pipeline<RedisClient> {
+    Pipeline::from(self.clone())
+  }
+
+  /// Read an interface for interacting exclusively with replica nodes.
+  pub fn replicas(&self) -> Replicas {
+    Replicas::from(&self.inner)
+  }
+}
@@ -34,12 +34,27 @@ use tokio::sync::oneshot::channel as oneshot_channel;
 /// are promoted. The cached replica routing table will be updated on the client when following cluster redirections
 /// or when any connection closes.
 ///
+/// Callers who would rather not manage a separate client handle can instead enable
+/// [read_from_replicas](crate::types::config::ReplicaConfig::read_from_replicas) on a regular
+/// [RedisClient](crate::clients::RedisClient), which flags read-only commands so the router sends them to a
+/// replica and write commands to the primary, reusing the same connection state described here.
+///
+/// Since [replication is asynchronous](https://redis.io/docs/management/replication/), a replica's view of the
+/// keyspace can lag behind its primary. [max_lag](crate::types::config::ReplicaConfig::max_lag) and
+/// [ReplicaOffsetTracker](crate::router::replica_staleness::ReplicaOffsetTracker) exist to stop routing reads to
+/// replicas that fall too far behind, though the periodic `INFO replication` poll that would keep the tracker
+/// current isn't wired into connection setup yet (see that module's docs).
+///
 /// [Redis replication is asynchronous](https://redis.io/docs/management/replication/).
 // ### Cluster Replication
 //
 // In a clustered deployment replicas may redirect callers back to primary nodes, even with read-only commands,
 // depending on the server configuration. The client will automatically follow these redirections, but callers should
-// be aware of this behavior for monitoring or tracing purposes.
+// be aware of this behavior for monitoring or tracing purposes. [Replicas::redirects] and [Replicas::on_redirect]
+// report the redirects recorded by the client's redirect tracker, and
+// [redirect_resync_threshold](crate::types::config::ReplicaConfig::redirect_resync_threshold) sets the count at
+// which a recorded redirect triggers a resync. No response-handling path in this crate records redirects, so the
+// counts stay at zero and no event or resync happens.
 //
 // #### Example
 //
@@ -128,6 +143,22 @@ impl Replicas {
     self.inner.server_state.read().replicas.clone()
   }
 
+  /// Read the replica nodes associated with a specific primary, if any.
+  ///
+  /// The configured [ReplicaRoutingPolicy](crate::types::config::ReplicaRoutingPolicy) for choosing among them is
+  /// applied only by [destination_for](crate::router::replica_routing::destination_for), which nothing in this
+  /// crate calls.
+  pub fn nodes_for(&self, primary: &Server) -> Vec<Server> {
+    self
+      .inner
+      .server_state
+      .read()
+      .replicas
+      .iter()
+      .filter_map(|(replica, replica_primary)| if replica_primary == primary { Some(replica.clone()) } else { None })
+      .collect()
+  }
+
   /// Send a series of commands in a [pipeline](https://redis.io/docs/manual/pipelining/).
   pub fn pipeline(&self) -> Pipeline<Replicas> {
     Pipeline::from(self.clone())
@@ -138,6 +169,16 @@ impl Replicas {
     RedisClient::from(&self.inner)
   }
 
+  /// Read the last known replication offset and lag for a replica, if known.
+  ///
+  /// The values come from the primary's `INFO replication` output via
+  /// [poll_replica_offsets](crate::router::replica_staleness::poll_replica_offsets), which nothing in this crate
+  /// runs on a schedule, so this returns `None` until offsets are recorded. Recorded lag is what
+  /// [max_lag](crate::types::config::ReplicaConfig::max_lag) is compared against.
+  pub fn offset(&self, replica: &Server) -> Option<crate::router::replica_staleness::ReplicaOffset> {
+    self.inner.replica_offsets.get(replica)
+  }
+
   /// Sync the cached replica routing table with the server(s).
   ///
   /// This will also disconnect and reset any replica connections.
@@ -147,4 +188,23 @@ impl Replicas {
     let _ = interfaces::send_to_router(&self.inner, cmd)?;
     rx.await?
   }
+
+  /// Read the number of `MOVED` redirects recorded from a replica since its count was last reset, either by
+  /// reaching [redirect_resync_threshold](crate::types::config::ReplicaConfig::redirect_resync_threshold) or by
+  /// [ReplicaRedirectTracker::reset](crate::router::replica_redirects::ReplicaRedirectTracker::reset). A
+  /// [sync](Self::sync) does not reset it.
+  pub fn redirects(&self, replica: &Server) -> u32 {
+    self.inner.replica_redirects.count(replica)
+  }
+
+  /// Subscribe to [ReplicaRedirect](crate::router::replica_redirects::ReplicaRedirect) events, emitted each time a
+  /// replica's `MOVED` redirect is recorded with the client's redirect tracker.
+  ///
+  /// Recording goes through [handle_replica_redirect](crate::router::replica_redirects::handle_replica_redirect),
+  /// which also re-runs a sync once a replica's count reaches
+  /// [redirect_resync_threshold](crate::types::config::ReplicaConfig::redirect_resync_threshold). Nothing in this
+  /// crate calls it, so no events are emitted and no automatic [sync](Self::sync) happens.
+  pub fn on_redirect(&self) -> tokio::sync::broadcast::Receiver<crate::router::replica_redirects::ReplicaRedirect> {
+    self.inner.replica_redirects.subscribe()
+  }
 }
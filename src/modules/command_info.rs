@@ -0,0 +1,222 @@
+use crate::{error::RedisError, modules::inner::RedisClientInner, router::replica_routing, types::{RedisValue, Server}};
+use std::{collections::HashMap, future::Future};
+
+/// Metadata about a single Redis command, derived from the server's `COMMAND` output.
+///
+/// See the [COMMAND](https://redis.io/commands/command/) documentation for the meaning of each field.
+#[derive(Clone, Debug, Default)]
+pub struct CommandInfo {
+  pub name:      String,
+  pub arity:     i64,
+  pub readonly:  bool,
+  pub write:     bool,
+  pub first_key: i64,
+  pub last_key:  i64,
+  pub step:      i64,
+}
+
+impl CommandInfo {
+  /// Return the positions of every key in `args`, based on `first_key`, `last_key`, and `step`.
+  ///
+  /// `num_args` is the number of arguments following the command name.
+  pub fn key_positions(&self, num_args: usize) -> Vec<usize> {
+    if self.step <= 0 || self.first_key <= 0 {
+      return Vec::new();
+    }
+
+    let first = self.first_key as usize;
+    let last = if self.last_key < 0 {
+      num_args.saturating_sub((self.last_key.unsigned_abs() as usize).saturating_sub(1))
+    } else {
+      self.last_key as usize
+    };
+
+    let mut positions = Vec::new();
+    let mut idx = first;
+    while idx <= last && idx <= num_args && idx != 0 {
+      positions.push(idx - 1);
+      idx += self.step as usize;
+    }
+    positions
+  }
+}
+
+/// A cached table of [CommandInfo], keyed by the uppercase command name.
+///
+/// [refresh_command_info] fills it by issuing `COMMAND`; if `COMMAND` is unavailable or every node fails to
+/// respond to it, [CommandInfoTable::fallback] is used instead so that read/write splitting and key extraction
+/// keep working with a smaller, hardcoded set of commands. No connection-setup code calls
+/// [refresh_command_info], so a client's table stays at its empty default.
+#[derive(Clone, Debug, Default)]
+pub struct CommandInfoTable {
+  commands: HashMap<String, CommandInfo>,
+}
+
+impl CommandInfoTable {
+  /// Look up the cached metadata for `name`, if any.
+  pub fn get(&self, name: &str) -> Option<&CommandInfo> {
+    self.commands.get(&name.to_uppercase())
+  }
+
+  /// Whether `name` is known to be read-only.
+  ///
+  /// Falls back to [replica_routing::is_readonly_command] when the table has no entry for `name`, which can
+  /// happen when the table was built from [CommandInfoTable::fallback] or the server doesn't report the command.
+  pub fn is_readonly(&self, name: &str) -> bool {
+    match self.get(name) {
+      Some(info) => info.readonly,
+      None => replica_routing::is_readonly_command(name),
+    }
+  }
+
+  /// Whether the table has no cached commands.
+  pub fn is_empty(&self) -> bool {
+    self.commands.is_empty()
+  }
+
+  /// Build a table from the reply to a `COMMAND` call.
+  ///
+  /// Each element is expected to be an array shaped like `[name, arity, flags, first_key, last_key, step]`.
+  /// Elements that don't match this shape are skipped rather than treated as a hard error, since some servers
+  /// include extra trailing fields (ACL categories, tips, key specs, sub-commands) that this client doesn't need.
+  pub fn from_command_reply(reply: &RedisValue) -> CommandInfoTable {
+    let mut commands = HashMap::new();
+
+    if let Some(rows) = reply.as_array() {
+      for row in rows.iter() {
+        let Some(fields) = row.as_array() else {
+          continue;
+        };
+        if fields.len() < 6 {
+          continue;
+        }
+
+        let Some(name) = fields[0].as_string() else {
+          continue;
+        };
+        let Some(arity) = fields[1].as_i64() else {
+          continue;
+        };
+        let flags: Vec<String> = fields[2]
+          .as_array()
+          .map(|flags| flags.iter().filter_map(|flag| flag.as_string()).collect())
+          .unwrap_or_default();
+        let Some(first_key) = fields[3].as_i64() else {
+          continue;
+        };
+        let Some(last_key) = fields[4].as_i64() else {
+          continue;
+        };
+        let Some(step) = fields[5].as_i64() else {
+          continue;
+        };
+
+        let info = CommandInfo {
+          readonly: flags.iter().any(|flag| flag == "readonly"),
+          write: flags.iter().any(|flag| flag == "write"),
+          name: name.to_uppercase(),
+          arity,
+          first_key,
+          last_key,
+          step,
+        };
+        commands.insert(info.name.clone(), info);
+      }
+    }
+
+    CommandInfoTable { commands }
+  }
+
+  /// Build a table from the hardcoded list of read-only commands used before `COMMAND` support existed.
+  ///
+  /// Used when `COMMAND` isn't supported or every node fails to respond to it.
+  pub fn fallback() -> CommandInfoTable {
+    let commands = replica_routing::READONLY_COMMANDS
+      .iter()
+      .map(|name| {
+        (name.to_string(), CommandInfo {
+          name: name.to_string(),
+          readonly: true,
+          write: false,
+          ..Default::default()
+        })
+      })
+      .collect();
+
+    CommandInfoTable { commands }
+  }
+}
+
+/// Refresh `inner`'s cached [CommandInfoTable] by issuing `COMMAND` against `servers`, via `send_command`.
+///
+/// Intended to be called once per connection during connection setup, after the router has a server list to
+/// connect to, so that `inner.command_info` reflects the real server instead of staying at its
+/// [Default](CommandInfoTable::default) (empty) value. No connection-setup code in this crate calls this yet, so
+/// `command_info` stays empty and [CommandInfoTable::is_readonly] silently falls through to
+/// [replica_routing::is_readonly_command] on every lookup until this is wired in. Servers are tried in order; the
+/// first one that returns a parseable reply wins. If every server errors or returns something
+/// [CommandInfoTable::from_command_reply] can't parse into any entries, `inner.command_info` falls back to
+/// [CommandInfoTable::fallback] so read/write splitting and key extraction keep working with a smaller, hardcoded
+/// command list.
+pub(crate) async fn refresh_command_info<F, Fut>(inner: &RedisClientInner, servers: &[Server], send_command: F)
+where
+  F: Fn(Server) -> Fut,
+  Fut: Future<Output = Result<RedisValue, RedisError>>,
+{
+  for server in servers {
+    if let Ok(reply) = send_command(server.clone()).await {
+      let table = CommandInfoTable::from_command_reply(&reply);
+      if !table.is_empty() {
+        *inner.command_info.write() = table;
+        return;
+      }
+    }
+  }
+
+  *inner.command_info.write() = CommandInfoTable::fallback();
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn info(first_key: i64, last_key: i64, step: i64) -> CommandInfo {
+    CommandInfo {
+      first_key,
+      last_key,
+      step,
+      ..Default::default()
+    }
+  }
+
+  #[test]
+  fn should_compute_key_positions_for_single_key_commands() {
+    // GET key -> key is the only argument, at position 1
+    assert_eq!(info(1, 1, 1).key_positions(1), vec![0]);
+  }
+
+  #[test]
+  fn should_compute_key_positions_with_a_step() {
+    // MSET key1 val1 key2 val2 -> keys at positions 1 and 3, step 2
+    assert_eq!(info(1, -1, 2).key_positions(4), vec![0, 2]);
+  }
+
+  #[test]
+  fn should_compute_key_positions_with_a_fixed_last_key() {
+    // GETRANGE key start end -> key at position 1 only
+    assert_eq!(info(1, 1, 1).key_positions(3), vec![0]);
+  }
+
+  #[test]
+  fn should_return_no_keys_for_commands_without_key_positions() {
+    // e.g. PING, COMMAND
+    assert_eq!(info(0, 0, 0).key_positions(0), Vec::<usize>::new());
+  }
+
+  #[test]
+  fn should_build_a_nonempty_fallback_table_of_readonly_commands() {
+    let table = CommandInfoTable::fallback();
+    assert!(table.is_readonly("get"));
+    assert!(!table.is_empty());
+  }
+}
@@ -0,0 +1,34 @@
+use crate::{
+  modules::command_info::CommandInfoTable,
+  router::{
+    replica_redirects::ReplicaRedirectTracker,
+    replica_selector::ReplicaSelector,
+    replica_staleness::ReplicaOffsetTracker,
+  },
+  types::{config::RedisConfig, Server},
+};
+use parking_lot::RwLock;
+use std::{collections::HashMap, sync::Arc};
+
+/// Server-side routing state that changes as the topology changes, e.g. after a cluster or replica
+/// [sync](crate::clients::Replicas::sync).
+#[derive(Default)]
+pub struct ServerState {
+  /// A mapping of replica server IDs to the primary server ID they replicate from.
+  pub replicas: HashMap<Server, Server>,
+}
+
+/// Shared state for a client instance.
+///
+/// This only tracks the fields consulted by the replica read/write splitting subsystem (config, the routing
+/// table, and command introspection); the rest of the client's shared state (connections, command queues, etc.)
+/// lives alongside these fields.
+pub struct RedisClientInner {
+  pub id:                String,
+  pub config:            Arc<RedisConfig>,
+  pub server_state:      RwLock<ServerState>,
+  pub command_info:      RwLock<CommandInfoTable>,
+  pub replica_selector:  ReplicaSelector,
+  pub replica_offsets:   ReplicaOffsetTracker,
+  pub replica_redirects: ReplicaRedirectTracker,
+}
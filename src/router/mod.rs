@@ -0,0 +1,12 @@
+//! Replica read/write splitting.
+//!
+//! The live integration point is [RedisClient::change_command](crate::clients::RedisClient), which flags a
+//! command's [use_replica](crate::protocol::command::RedisCommand::use_replica) based on
+//! [replica_routing::should_use_replica]. The remaining pieces in these submodules
+//! ([replica_selector], [replica_staleness], [replica_redirects]) implement the rest of the backlog (replica
+//! selection policy, staleness filtering, and redirect tracking/auto-resync) but are not yet called from a
+//! connection-checkout or response-handling path in this crate; see their module docs for what's pending.
+pub mod replica_redirects;
+pub mod replica_routing;
+pub mod replica_selector;
+pub mod replica_staleness;
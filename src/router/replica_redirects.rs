@@ -0,0 +1,175 @@
+use crate::{error::RedisError, modules::inner::RedisClientInner, types::Server};
+use parking_lot::RwLock;
+use std::collections::HashMap;
+use tokio::sync::broadcast::{self, Receiver, Sender};
+
+/// An event emitted when a replica responds to a read-only command with `MOVED`, redirecting it back to its
+/// primary.
+#[derive(Clone, Debug)]
+pub struct ReplicaRedirect {
+  /// The replica that sent the redirect.
+  pub replica: Server,
+  /// The primary the command was redirected to.
+  pub primary: Server,
+  /// The number of redirects recorded from `replica` since its count was last reset (by reaching the resync
+  /// threshold or by [ReplicaRedirectTracker::reset]), including this one.
+  pub count:   u32,
+}
+
+/// Tracks per-replica `MOVED` redirect counts and notifies subscribers when one occurs.
+///
+/// This lets callers monitor how often reads are silently bounced back to the primary, and lets the router treat
+/// repeated redirects from the same replica as a signal that its cached routing table is stale.
+#[derive(Debug)]
+pub struct ReplicaRedirectTracker {
+  counts: RwLock<HashMap<Server, u32>>,
+  tx:     Sender<ReplicaRedirect>,
+}
+
+impl Default for ReplicaRedirectTracker {
+  fn default() -> Self {
+    let (tx, _) = broadcast::channel(16);
+    ReplicaRedirectTracker {
+      counts: RwLock::new(HashMap::new()),
+      tx,
+    }
+  }
+}
+
+impl ReplicaRedirectTracker {
+  /// Subscribe to redirect events.
+  pub fn subscribe(&self) -> Receiver<ReplicaRedirect> {
+    self.tx.subscribe()
+  }
+
+  /// Record a redirect from `replica` back to `primary`.
+  ///
+  /// Returns `true` if the count for `replica` has reached `resync_threshold`, in which case the caller should
+  /// re-run [Replicas::sync](crate::clients::Replicas::sync) and the count is reset.
+  pub fn record(&self, replica: &Server, primary: &Server, resync_threshold: Option<u32>) -> bool {
+    let count = {
+      let mut counts = self.counts.write();
+      let count = counts.entry(replica.clone()).or_insert(0);
+      *count += 1;
+      *count
+    };
+
+    let _ = self.tx.send(ReplicaRedirect {
+      replica: replica.clone(),
+      primary: primary.clone(),
+      count,
+    });
+
+    let should_resync = resync_threshold.map(|threshold| count >= threshold).unwrap_or(false);
+    if should_resync {
+      self.counts.write().remove(replica);
+    }
+    should_resync
+  }
+
+  /// Read the current redirect count for `replica`.
+  pub fn count(&self, replica: &Server) -> u32 {
+    self.counts.read().get(replica).copied().unwrap_or(0)
+  }
+
+  /// Reset the redirect count for `replica`, typically after a successful [sync](crate::clients::Replicas::sync).
+  pub fn reset(&self, replica: &Server) {
+    self.counts.write().remove(replica);
+  }
+}
+
+/// Record that `replica` redirected a read-only command to `primary` with `MOVED`, and resync once
+/// [redirect_resync_threshold](crate::types::config::ReplicaConfig::redirect_resync_threshold) consecutive
+/// redirects have been observed from it.
+///
+/// Intended to be called from wherever the router parses a command's reply and notices a `MOVED` error from a
+/// connection it only expected read-only replies from. No such response-handling path exists in this crate yet,
+/// so this function is not reachable outside its own unit tests; it records the redirect (emitting a
+/// [ReplicaRedirect] event to any subscriber) and, past the threshold, re-runs the provided `sync` closure (e.g.
+/// [Replicas::sync](crate::clients::Replicas::sync)) so the cached routing table can self-heal once this is wired
+/// up.
+pub(crate) async fn handle_replica_redirect<F, Fut>(inner: &RedisClientInner, replica: &Server, primary: &Server, sync: F)
+where
+  F: FnOnce() -> Fut,
+  Fut: std::future::Future<Output = Result<(), RedisError>>,
+{
+  let should_resync = inner
+    .replica_redirects
+    .record(replica, primary, inner.config.replica.redirect_resync_threshold);
+
+  if should_resync {
+    let _ = sync().await;
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn server(port: u16) -> Server {
+    Server::new("127.0.0.1", port)
+  }
+
+  #[test]
+  fn should_count_redirects_per_replica() {
+    let tracker = ReplicaRedirectTracker::default();
+    let replica = server(30001);
+    let primary = server(30000);
+
+    assert!(!tracker.record(&replica, &primary, None));
+    assert!(!tracker.record(&replica, &primary, None));
+    assert_eq!(tracker.count(&replica), 2);
+  }
+
+  #[test]
+  fn should_signal_resync_once_threshold_is_reached() {
+    let tracker = ReplicaRedirectTracker::default();
+    let replica = server(30001);
+    let primary = server(30000);
+
+    assert!(!tracker.record(&replica, &primary, Some(3)));
+    assert!(!tracker.record(&replica, &primary, Some(3)));
+    assert!(tracker.record(&replica, &primary, Some(3)));
+    // the count resets once the threshold triggers a resync
+    assert_eq!(tracker.count(&replica), 0);
+  }
+
+  #[test]
+  fn should_never_signal_resync_without_a_threshold() {
+    let tracker = ReplicaRedirectTracker::default();
+    let replica = server(30001);
+    let primary = server(30000);
+
+    for _ in 0 .. 10 {
+      assert!(!tracker.record(&replica, &primary, None));
+    }
+    assert_eq!(tracker.count(&replica), 10);
+  }
+
+  #[test]
+  fn should_track_redirect_counts_independently_per_replica() {
+    let tracker = ReplicaRedirectTracker::default();
+    let primary = server(30000);
+    let replica_a = server(30001);
+    let replica_b = server(30002);
+
+    tracker.record(&replica_a, &primary, None);
+    assert_eq!(tracker.count(&replica_a), 1);
+    assert_eq!(tracker.count(&replica_b), 0);
+  }
+
+  #[test]
+  fn should_emit_an_event_on_subscribe_when_a_redirect_is_recorded() {
+    let tracker = ReplicaRedirectTracker::default();
+    let mut rx = tracker.subscribe();
+    let replica = server(30001);
+    let primary = server(30000);
+
+    tracker.record(&replica, &primary, None);
+
+    let event = rx.try_recv().expect("expected a redirect event");
+    assert_eq!(event.replica, replica);
+    assert_eq!(event.primary, primary);
+    assert_eq!(event.count, 1);
+  }
+}
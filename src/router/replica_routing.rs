@@ -0,0 +1,99 @@
+use crate::{
+  interfaces::ClientLike,
+  modules::{command_info::CommandInfoTable, inner::RedisClientInner},
+  types::{config::ReplicaConfig, Server},
+};
+
+/// A hardcoded set of read-only command names, used as a fallback for routing decisions when no dynamic
+/// command classification (see [CommandInfoTable]) is available.
+pub(crate) const READONLY_COMMANDS: &[&str] = &[
+  "GET", "MGET", "GETRANGE", "STRLEN", "EXISTS", "TTL", "PTTL", "TYPE", "KEYS", "SCAN", "DBSIZE", "RANDOMKEY",
+  "HGET", "HMGET", "HGETALL", "HKEYS", "HVALS", "HLEN", "HEXISTS", "HSTRLEN", "SMEMBERS", "SISMEMBER", "SCARD",
+  "SRANDMEMBER", "ZSCORE", "ZRANGE", "ZRANGEBYSCORE", "ZRANGEBYLEX", "ZCARD", "ZRANK", "ZREVRANK", "LRANGE",
+  "LLEN", "LINDEX", "XRANGE", "XREVRANGE", "XLEN", "GETBIT", "BITCOUNT", "BITPOS",
+];
+
+/// Check whether `command_name` is safe to route to a replica node.
+///
+/// This currently relies on a hardcoded list of read-only commands.
+pub fn is_readonly_command(command_name: &str) -> bool {
+  READONLY_COMMANDS.contains(&command_name.to_uppercase().as_str())
+}
+
+/// Decide whether the router should use a replica connection for a command with the given name.
+///
+/// Returns `false` (i.e. route to the primary) when automatic replica routing is disabled, when the command is
+/// not read-only according to `command_info`, or when `has_replica` is `false`.
+/// [RedisClient::change_command](crate::clients::RedisClient) passes whether any replica is known at all, while
+/// [destination_for] passes whether one is known for the destination primary.
+pub fn should_use_replica(
+  config: &ReplicaConfig,
+  has_replica: bool,
+  command_name: &str,
+  command_info: &CommandInfoTable,
+) -> bool {
+  config.read_from_replicas && has_replica && command_info.is_readonly(command_name)
+}
+
+/// Choose which specific server a command bound for `primary` should be sent to, once the router has already
+/// decided (via [RedisClient::change_command](crate::clients::RedisClient) setting
+/// [RedisCommand::use_replica](crate::protocol::command::RedisCommand::use_replica)) that a replica is acceptable.
+///
+/// This combines [ReplicaSelector](crate::router::replica_selector::ReplicaSelector) and
+/// [ReplicaOffsetTracker](crate::router::replica_staleness::ReplicaOffsetTracker) to pick among the replicas known
+/// for `primary`, skipping stale ones and falling back to `primary` if none remain.
+///
+/// Nothing in this crate invokes this yet: the connection-checkout step that would call it, once a command is
+/// already flagged `use_replica`, lives in the router's connection pool and hasn't been updated for this. Treat
+/// it as ready for that integration rather than as something already on the hot path.
+pub(crate) fn destination_for(inner: &RedisClientInner, primary: &Server, command_name: &str) -> Server {
+  let has_replica = inner
+    .server_state
+    .read()
+    .replicas
+    .values()
+    .any(|candidate_primary| candidate_primary == primary);
+
+  if !should_use_replica(
+    &inner.config.replica,
+    has_replica,
+    command_name,
+    &inner.command_info.read(),
+  ) {
+    return primary.clone();
+  }
+
+  let candidates: Vec<Server> = inner
+    .server_state
+    .read()
+    .replicas
+    .iter()
+    .filter(|(_, candidate_primary)| *candidate_primary == primary)
+    .map(|(replica, _)| replica.clone())
+    .filter(|replica| !inner.replica_offsets.is_stale(replica, inner.config.replica.max_lag))
+    .collect();
+
+  inner
+    .replica_selector
+    .select(&inner.config.replica.policy, &candidates)
+    .cloned()
+    .unwrap_or_else(|| primary.clone())
+}
+
+/// Extension trait that lets callers introspect the automatic read/write splitting mode on any [ClientLike]
+/// client. The actual routing decision is made per-command by
+/// [RedisClient::change_command](crate::clients::RedisClient), which calls [should_use_replica]; this trait only
+/// exposes the configured setting for inspection.
+///
+/// This is implemented for [RedisClient](crate::clients::RedisClient) and any other type that implements
+/// [ClientLike], including [Replicas](crate::clients::Replicas) itself.
+pub trait ReplicaRouting: ClientLike {
+  /// Whether this client automatically routes read-only commands to a replica node.
+  ///
+  /// See [ReplicaConfig::read_from_replicas].
+  fn read_from_replicas(&self) -> bool {
+    self.inner().config.replica.read_from_replicas
+  }
+}
+
+impl<T> ReplicaRouting for T where T: ClientLike {}
@@ -0,0 +1,134 @@
+use crate::types::{config::ReplicaRoutingPolicy, Server};
+use parking_lot::RwLock;
+use rand::Rng;
+use std::{
+  collections::HashMap,
+  sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+  },
+  time::Duration,
+};
+
+/// Tracks round-trip latency observed on each replica connection, used by [ReplicaRoutingPolicy::Nearest].
+#[derive(Clone, Debug, Default)]
+pub struct ReplicaLatencyTracker {
+  inner: Arc<RwLock<HashMap<Server, Duration>>>,
+}
+
+impl ReplicaLatencyTracker {
+  /// Record a newly observed round-trip time for `server`.
+  pub fn record(&self, server: &Server, rtt: Duration) {
+    self.inner.write().insert(server.clone(), rtt);
+  }
+
+  /// Read the last observed round-trip time for `server`, if any.
+  pub fn get(&self, server: &Server) -> Option<Duration> {
+    self.inner.read().get(server).copied()
+  }
+}
+
+/// Chooses among a set of candidate replica servers for the same primary, according to a [ReplicaRoutingPolicy].
+///
+/// Used by [destination_for](crate::router::replica_routing::destination_for) to pick a specific replica once the
+/// router has already decided one is acceptable. `destination_for` isn't called from anywhere in this crate yet
+/// (see its doc comment), so today `select` only runs from this module's own unit tests.
+#[derive(Debug, Default)]
+pub struct ReplicaSelector {
+  round_robin: AtomicUsize,
+  /// Observed latency per replica connection, used by [ReplicaRoutingPolicy::Nearest].
+  pub latency: ReplicaLatencyTracker,
+}
+
+impl ReplicaSelector {
+  /// Pick one server from `candidates` according to `policy`.
+  ///
+  /// Returns `None` if `candidates` is empty. When `policy` is [ReplicaRoutingPolicy::Nearest] and no latency has
+  /// been observed yet for any candidate this falls back to the first candidate.
+  pub fn select<'a>(&self, policy: &ReplicaRoutingPolicy, candidates: &'a [Server]) -> Option<&'a Server> {
+    if candidates.is_empty() {
+      return None;
+    }
+
+    match policy {
+      ReplicaRoutingPolicy::FirstAvailable => candidates.first(),
+      ReplicaRoutingPolicy::RoundRobin => {
+        let idx = self.round_robin.fetch_add(1, Ordering::Relaxed) % candidates.len();
+        candidates.get(idx)
+      },
+      ReplicaRoutingPolicy::Random => {
+        let idx = rand::thread_rng().gen_range(0 .. candidates.len());
+        candidates.get(idx)
+      },
+      ReplicaRoutingPolicy::Nearest => candidates
+        .iter()
+        .min_by_key(|server| self.latency.get(server).unwrap_or(Duration::MAX)),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn server(port: u16) -> Server {
+    Server::new("127.0.0.1", port)
+  }
+
+  #[test]
+  fn should_return_none_with_no_candidates() {
+    let selector = ReplicaSelector::default();
+    assert_eq!(selector.select(&ReplicaRoutingPolicy::RoundRobin, &[]), None);
+  }
+
+  #[test]
+  fn should_always_pick_first_available() {
+    let selector = ReplicaSelector::default();
+    let candidates = [server(1), server(2), server(3)];
+
+    for _ in 0 .. 3 {
+      assert_eq!(
+        selector.select(&ReplicaRoutingPolicy::FirstAvailable, &candidates),
+        Some(&candidates[0])
+      );
+    }
+  }
+
+  #[test]
+  fn should_cycle_round_robin() {
+    let selector = ReplicaSelector::default();
+    let candidates = [server(1), server(2), server(3)];
+
+    let picked: Vec<_> = (0 .. 4)
+      .map(|_| selector.select(&ReplicaRoutingPolicy::RoundRobin, &candidates).unwrap().clone())
+      .collect();
+
+    assert_eq!(picked, vec![candidates[0].clone(), candidates[1].clone(), candidates[2].clone(), candidates[0].clone()]);
+  }
+
+  #[test]
+  fn should_prefer_lowest_latency_for_nearest() {
+    let selector = ReplicaSelector::default();
+    let candidates = [server(1), server(2), server(3)];
+
+    selector.latency.record(&candidates[0], Duration::from_millis(50));
+    selector.latency.record(&candidates[1], Duration::from_millis(5));
+    selector.latency.record(&candidates[2], Duration::from_millis(20));
+
+    assert_eq!(
+      selector.select(&ReplicaRoutingPolicy::Nearest, &candidates),
+      Some(&candidates[1])
+    );
+  }
+
+  #[test]
+  fn should_fall_back_to_first_candidate_for_nearest_with_no_latency_data() {
+    let selector = ReplicaSelector::default();
+    let candidates = [server(1), server(2)];
+
+    assert_eq!(
+      selector.select(&ReplicaRoutingPolicy::Nearest, &candidates),
+      Some(&candidates[0])
+    );
+  }
+}
@@ -0,0 +1,157 @@
+use crate::{error::RedisError, modules::inner::RedisClientInner, types::Server};
+use parking_lot::RwLock;
+use std::{collections::HashMap, future::Future, sync::Arc};
+
+/// A replica's last known replication offset and lag, as reported by the primary's `INFO replication` output.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ReplicaOffset {
+  /// The replica's reported replication offset.
+  pub offset: u64,
+  /// The replica's reported lag, in seconds.
+  pub lag:    u64,
+}
+
+/// Parse the `slaveN:ip=...,port=...,state=...,offset=...,lag=...` lines out of a primary's `INFO replication`
+/// output.
+pub fn parse_replica_offsets(info: &str) -> HashMap<Server, ReplicaOffset> {
+  let mut out = HashMap::new();
+
+  for line in info.lines() {
+    if !line.starts_with("slave") {
+      continue;
+    }
+    let Some((_, fields)) = line.split_once(':') else {
+      continue;
+    };
+
+    let (mut ip, mut port, mut offset, mut lag) = (None, None, None, None);
+    for pair in fields.split(',') {
+      let Some((key, value)) = pair.split_once('=') else {
+        continue;
+      };
+      match key {
+        "ip" => ip = Some(value.to_string()),
+        "port" => port = value.parse::<u16>().ok(),
+        "offset" => offset = value.parse::<u64>().ok(),
+        "lag" => lag = value.parse::<u64>().ok(),
+        _ => {},
+      }
+    }
+
+    if let (Some(ip), Some(port)) = (ip, port) {
+      out.insert(Server::new(ip, port), ReplicaOffset {
+        offset: offset.unwrap_or(0),
+        lag:    lag.unwrap_or(0),
+      });
+    }
+  }
+
+  out
+}
+
+/// Tracks the last known replication offset and lag for each replica.
+#[derive(Clone, Debug, Default)]
+pub struct ReplicaOffsetTracker {
+  inner: Arc<RwLock<HashMap<Server, ReplicaOffset>>>,
+}
+
+impl ReplicaOffsetTracker {
+  /// Replace the cached offsets, typically with the result of [parse_replica_offsets].
+  pub fn update(&self, offsets: HashMap<Server, ReplicaOffset>) {
+    *self.inner.write() = offsets;
+  }
+
+  /// Merge freshly observed `offsets` into the cache, leaving previously cached entries for other replicas
+  /// untouched.
+  pub fn merge(&self, offsets: HashMap<Server, ReplicaOffset>) {
+    self.inner.write().extend(offsets);
+  }
+
+  /// Read the last known offset and lag for `replica`, if any.
+  pub fn get(&self, replica: &Server) -> Option<ReplicaOffset> {
+    self.inner.read().get(replica).copied()
+  }
+
+  /// Whether `replica` should be skipped given `max_lag`.
+  ///
+  /// Replicas with no recorded offset yet are never considered stale, since the poller may not have run yet.
+  pub fn is_stale(&self, replica: &Server, max_lag: Option<u64>) -> bool {
+    match max_lag {
+      None => false,
+      Some(max_lag) => self.get(replica).map(|info| info.lag > max_lag).unwrap_or(false),
+    }
+  }
+}
+
+/// Poll `primary`'s `INFO replication` output, via `send_info`, and refresh `inner.replica_offsets` with the
+/// result.
+///
+/// Meant to be called on an interval (e.g. from a background task spawned alongside the connection, one per
+/// primary) after the replica routing table has been synced, so that [max_lag](crate::types::config::ReplicaConfig::max_lag)
+/// filtering in [destination_for](crate::router::replica_routing::destination_for) has real data to work with. No
+/// such background task exists in this crate yet, so `inner.replica_offsets` is never populated outside of tests
+/// and every replica is treated as not stale regardless of `max_lag`. A failed `INFO` call leaves the previously
+/// cached offsets in place rather than clearing them, since a single failed poll shouldn't make otherwise healthy
+/// replicas look stale.
+pub(crate) async fn poll_replica_offsets<F, Fut>(inner: &RedisClientInner, primary: &Server, send_info: F)
+where
+  F: FnOnce(Server) -> Fut,
+  Fut: Future<Output = Result<String, RedisError>>,
+{
+  if let Ok(info) = send_info(primary.clone()).await {
+    let offsets = parse_replica_offsets(&info);
+    if !offsets.is_empty() {
+      inner.replica_offsets.merge(offsets);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const SAMPLE_INFO: &str = "# Replication\nrole:master\nconnected_slaves:2\n\
+slave0:ip=127.0.0.1,port=30001,state=online,offset=183696,lag=0\n\
+slave1:ip=127.0.0.1,port=30002,state=online,offset=183600,lag=12\n";
+
+  #[test]
+  fn should_parse_replica_offsets_from_info_output() {
+    let offsets = parse_replica_offsets(SAMPLE_INFO);
+
+    assert_eq!(offsets.len(), 2);
+    assert_eq!(offsets.get(&Server::new("127.0.0.1", 30001)), Some(&ReplicaOffset { offset: 183696, lag: 0 }));
+    assert_eq!(offsets.get(&Server::new("127.0.0.1", 30002)), Some(&ReplicaOffset { offset: 183600, lag: 12 }));
+  }
+
+  #[test]
+  fn should_ignore_lines_that_are_not_slave_entries() {
+    let offsets = parse_replica_offsets("# Replication\nrole:master\nconnected_slaves:0\n");
+    assert!(offsets.is_empty());
+  }
+
+  #[test]
+  fn should_never_consider_a_replica_stale_with_no_max_lag() {
+    let tracker = ReplicaOffsetTracker::default();
+    let replica = Server::new("127.0.0.1", 30001);
+    tracker.update(HashMap::from([(replica.clone(), ReplicaOffset { offset: 1, lag: 9_999 })]));
+
+    assert!(!tracker.is_stale(&replica, None));
+  }
+
+  #[test]
+  fn should_consider_a_replica_stale_once_lag_exceeds_the_threshold() {
+    let tracker = ReplicaOffsetTracker::default();
+    let replica = Server::new("127.0.0.1", 30001);
+    tracker.update(HashMap::from([(replica.clone(), ReplicaOffset { offset: 1, lag: 10 })]));
+
+    assert!(!tracker.is_stale(&replica, Some(10)));
+    assert!(tracker.is_stale(&replica, Some(9)));
+  }
+
+  #[test]
+  fn should_not_consider_an_unknown_replica_stale() {
+    let tracker = ReplicaOffsetTracker::default();
+    let replica = Server::new("127.0.0.1", 30001);
+    assert!(!tracker.is_stale(&replica, Some(0)));
+  }
+}
@@ -0,0 +1,84 @@
+/// Top-level configuration options for a client instance.
+///
+/// This only tracks the fields consulted by the replica read/write splitting subsystem; the rest of the client's
+/// configuration (connection, TLS, cluster discovery, etc.) lives alongside `replica` on the same struct.
+#[derive(Clone, Debug, Default)]
+pub struct RedisConfig {
+  /// Options controlling replica routing. See [ReplicaConfig].
+  pub replica: ReplicaConfig,
+}
+
+/// Configuration options for replica (read-only) nodes.
+///
+/// These options control both the explicit [Replicas](crate::clients::Replicas) interface and, when
+/// `read_from_replicas` is enabled, the automatic read/write splitting performed by the router on behalf of a
+/// regular [RedisClient](crate::clients::RedisClient).
+#[derive(Clone, Debug)]
+pub struct ReplicaConfig {
+  /// Whether clients should automatically route read-only commands to a replica node and write commands to the
+  /// primary node.
+  ///
+  /// When enabled the router inspects each [RedisCommand](crate::protocol::command::RedisCommand) and decides
+  /// where to send it without requiring callers to go through the [Replicas](crate::clients::Replicas) interface.
+  /// A command is flagged for a replica only while at least one replica is known; when none is known it is sent to
+  /// the primary. Whether a replica is known for the command's own primary is not checked at this point.
+  ///
+  /// Default: `false`
+  pub read_from_replicas: bool,
+  /// The policy to use when more than one replica is available for the same primary.
+  ///
+  /// It is applied by [destination_for](crate::router::replica_routing::destination_for), which nothing in this
+  /// crate calls, so this setting does not change which replica serves a command.
+  ///
+  /// Default: [ReplicaRoutingPolicy::RoundRobin]
+  pub policy: ReplicaRoutingPolicy,
+  /// The maximum replication lag, in seconds as reported by the primary's `INFO replication` output, that a
+  /// replica may have before [destination_for](crate::router::replica_routing::destination_for) skips it.
+  ///
+  /// When `None` replication lag is ignored. When `Some` and every replica for a primary exceeds this threshold
+  /// `destination_for` returns the primary. Nothing in this crate calls `destination_for` or polls replica
+  /// offsets, so this setting does not change where commands are sent.
+  ///
+  /// Default: `None`
+  pub max_lag: Option<u64>,
+  /// The number of recorded `MOVED` redirects from a single replica at which
+  /// [handle_replica_redirect](crate::router::replica_redirects::handle_replica_redirect) re-runs the sync it is
+  /// given (e.g. [Replicas::sync](crate::clients::Replicas::sync)) and resets that replica's count. Nothing in this
+  /// crate calls `handle_replica_redirect`, so no automatic resync happens.
+  ///
+  /// Default: `None` (never automatically resync because of redirects)
+  pub redirect_resync_threshold: Option<u32>,
+}
+
+impl Default for ReplicaConfig {
+  fn default() -> Self {
+    ReplicaConfig {
+      read_from_replicas: false,
+      policy: ReplicaRoutingPolicy::default(),
+      max_lag: None,
+      redirect_resync_threshold: None,
+    }
+  }
+}
+
+/// The policy used to choose among multiple replicas associated with the same primary node.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ReplicaRoutingPolicy {
+  /// Cycle through the known replicas for a primary in order.
+  RoundRobin,
+  /// Pick a replica at random.
+  Random,
+  /// Always use the first candidate replica for a primary. Connection availability is not checked; another
+  /// replica is used only when the first one is not among the candidates, e.g. because it exceeds `max_lag`.
+  FirstAvailable,
+  /// Prefer the replica with the lowest round-trip time recorded in
+  /// [ReplicaSelector::latency](crate::router::replica_selector::ReplicaSelector::latency); replicas with no
+  /// recorded time rank last. Nothing in this crate records these times automatically.
+  Nearest,
+}
+
+impl Default for ReplicaRoutingPolicy {
+  fn default() -> Self {
+    ReplicaRoutingPolicy::RoundRobin
+  }
+}